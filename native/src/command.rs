@@ -0,0 +1,130 @@
+//! Run asynchronous actions.
+mod action;
+
+pub use action::Action;
+
+use crate::window;
+
+/// A set of asynchronous actions to be performed by some `Application`.
+#[allow(missing_debug_implementations)]
+pub struct Command<T> {
+    actions: Vec<Action<T>>,
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn none() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs a single [`Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Action`]: enum.Action.html
+    pub fn single(action: Action<T>) -> Self {
+        Self {
+            actions: vec![action],
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given `Future`, mapping
+    /// its output with `f`.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn perform<A>(
+        future: impl std::future::Future<Output = A> + 'static + Send,
+        f: impl FnOnce(A) -> T + 'static + Send,
+    ) -> Command<T>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Self::single(Action::Future(Box::pin(async move { f(future.await) })))
+    }
+
+    /// Creates a [`Command`] that changes the [`Mode`] of the window.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Mode`]: ../window/enum.Mode.html
+    pub fn set_mode(mode: window::Mode) -> Command<T> {
+        Self::single(Action::Window(window::Action::ChangeMode(mode)))
+    }
+
+    /// Creates a [`Command`] that starts an interactive move of the
+    /// window, driven by the OS drag gesture already in progress.
+    ///
+    /// This should be produced in response to a press on an
+    /// application-drawn title bar.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn drag_window() -> Command<T> {
+        Self::single(Action::Window(window::Action::Drag))
+    }
+
+    /// Creates a [`Command`] that minimizes the window.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn minimize_window() -> Command<T> {
+        Self::single(Action::Window(window::Action::Minimize))
+    }
+
+    /// Creates a [`Command`] that toggles the window between its
+    /// maximized and restored state.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn maximize_window() -> Command<T> {
+        Self::single(Action::Window(window::Action::Maximize))
+    }
+
+    /// Creates a [`Command`] that closes the window.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn close_window() -> Command<T> {
+        Self::single(Action::Window(window::Action::Close))
+    }
+
+    /// Combines the given commands into a single one.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        Self {
+            actions: commands
+                .into_iter()
+                .flat_map(|command| command.actions)
+                .collect(),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = std::sync::Arc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| action.map(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns all of the actions of the [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn actions(self) -> Vec<Action<T>> {
+        self.actions
+    }
+}
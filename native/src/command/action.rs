@@ -0,0 +1,47 @@
+use crate::window;
+use futures::future::BoxFuture;
+
+/// An action that a [`Command`] can perform.
+///
+/// [`Command`]: ../struct.Command.html
+pub enum Action<T> {
+    /// Run a `Future` to completion, mapping its output to a message.
+    Future(BoxFuture<'static, T>),
+
+    /// Run a window [`Action`].
+    ///
+    /// [`Action`]: ../../window/enum.Action.html
+    Window(window::Action),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of an [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::Future(future) => {
+                Action::Future(Box::pin(async move { f(future.await) }))
+            }
+            Action::Window(action) => Action::Window(action),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Future(_) => write!(f, "Action::Future"),
+            Action::Window(action) => {
+                write!(f, "Action::Window({:?})", action)
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![forbid(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+//! A renderer-agnostic library for native GUIs.
+pub mod command;
+pub mod input;
+pub mod layout;
+pub mod mouse_cursor;
+pub mod renderer;
+pub mod widget;
+pub mod window;
+
+mod clipboard;
+mod element;
+mod event;
+mod hasher;
+mod length;
+mod point;
+mod rectangle;
+
+pub use clipboard::Clipboard;
+pub use command::Command;
+pub use element::Element;
+pub use event::Event;
+pub use hasher::Hasher;
+pub use layout::Layout;
+pub use length::Length;
+pub use mouse_cursor::MouseCursor;
+pub use point::Point;
+pub use rectangle::Rectangle;
+pub use renderer::Renderer;
+pub use widget::*;
@@ -0,0 +1,42 @@
+//! Choose the cursor icon shown over a widget.
+
+/// The cursor icon a widget would like to be shown while it is hovered.
+///
+/// A renderer aggregates the [`MouseCursor`] reported by every widget in
+/// a frame and keeps whichever one belongs to the topmost hovered widget,
+/// so the windowing backend can update the OS cursor icon accordingly.
+///
+/// [`MouseCursor`]: enum.MouseCursor.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseCursor {
+    /// The cursor is a normal, unobtrusive pointer.
+    Default,
+
+    /// The cursor indicates an interactive, clickable element.
+    Pointer,
+
+    /// The cursor indicates selectable or editable text.
+    Text,
+
+    /// The cursor indicates something can be grabbed.
+    Grab,
+
+    /// The cursor indicates something is currently being grabbed.
+    Grabbing,
+
+    /// The cursor indicates horizontal resizing.
+    ResizeHorizontal,
+
+    /// The cursor indicates vertical resizing.
+    ResizeVertical,
+
+    /// The cursor indicates the widget does not accept the current
+    /// action.
+    NotAllowed,
+}
+
+impl Default for MouseCursor {
+    fn default() -> MouseCursor {
+        MouseCursor::Default
+    }
+}
@@ -0,0 +1,288 @@
+//! Let your users minimize, maximize, and close the window from a
+//! custom title bar.
+//!
+//! A [`CaptionButton`] has some local [`State`].
+//!
+//! [`CaptionButton`]: struct.CaptionButton.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{mouse, ButtonState},
+    layout, Clipboard, Command, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Widget,
+};
+use std::hash::Hash;
+
+/// A widget that mimics one of the caption buttons found on a native
+/// title bar (minimize, maximize/restore, or close) and produces a
+/// message when pressed.
+///
+/// It is meant to be placed inside a custom title bar built by the
+/// application when the window uses [`Decorations::Custom`]. On every
+/// platform, the actual window manipulation happens through the
+/// [`Command`] returned by [`Kind::to_command`], which the application
+/// should return from `update` when it receives the `Message` produced
+/// by [`on_press`] — there is no Windows-specific behavior to opt into
+/// or platform fallback to write here.
+///
+/// [`Decorations::Custom`]: ../../../window/enum.Decorations.html#variant.Custom
+/// [`Command`]: ../../command/struct.Command.html
+/// [`Kind::to_command`]: enum.Kind.html#method.to_command
+/// [`on_press`]: #method.on_press
+///
+/// ```
+/// # use iced_native::{caption_button, Text};
+/// #
+/// # type CaptionButton<'a, Message> =
+/// #     iced_native::CaptionButton<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     CaptionButtonPressed(caption_button::Kind),
+/// }
+///
+/// let mut state = caption_button::State::new();
+/// let close = CaptionButton::new(&mut state, caption_button::Kind::Close)
+///     .on_press(Message::CaptionButtonPressed(caption_button::Kind::Close));
+///
+/// // In `Application::update`:
+/// // Message::CaptionButtonPressed(kind) => kind.to_command(),
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CaptionButton<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    kind: Kind,
+    on_press: Option<Message>,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> CaptionButton<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`CaptionButton`] with some local [`State`] and the
+    /// given [`Kind`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    /// [`State`]: struct.State.html
+    /// [`Kind`]: enum.Kind.html
+    pub fn new(state: &'a mut State, kind: Kind) -> Self {
+        CaptionButton {
+            state,
+            kind,
+            on_press: None,
+            width: Length::Units(Renderer::DEFAULT_SIZE),
+            height: Length::Units(Renderer::DEFAULT_SIZE),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`CaptionButton`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CaptionButton`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`CaptionButton`]
+    /// is pressed.
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    pub fn on_press(mut self, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
+    /// Sets the style of the [`CaptionButton`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The kind of a [`CaptionButton`].
+///
+/// [`CaptionButton`]: struct.CaptionButton.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// Minimizes the window.
+    Minimize,
+    /// Toggles the window between its maximized and restored state.
+    Maximize,
+    /// Closes the window.
+    Close,
+}
+
+impl Kind {
+    /// Returns the [`Command`] that should be performed in response to
+    /// this [`Kind`] of caption button being pressed.
+    ///
+    /// [`Command`]: ../../command/struct.Command.html
+    /// [`Kind`]: enum.Kind.html
+    pub fn to_command<T>(self) -> Command<T> {
+        match self {
+            Kind::Minimize => Command::minimize_window(),
+            Kind::Maximize => Command::maximize_window(),
+            Kind::Close => Command::close_window(),
+        }
+    }
+}
+
+/// The local state of a [`CaptionButton`].
+///
+/// [`CaptionButton`]: struct.CaptionButton.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_pressed: bool,
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CaptionButton<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(layout::Limits::NONE.max());
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        self.state.is_hovered = bounds.contains(cursor_position);
+
+        if let Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state,
+        }) = event
+        {
+            match state {
+                ButtonState::Pressed => {
+                    self.state.is_pressed = self.state.is_hovered;
+                }
+                ButtonState::Released => {
+                    if self.state.is_pressed && self.state.is_hovered {
+                        if let Some(on_press) = self.on_press.clone() {
+                            messages.push(on_press);
+                        }
+                    }
+
+                    self.state.is_pressed = false;
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.kind,
+            self.on_press.is_none(),
+            self.state.is_pressed,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.kind.hash(state);
+    }
+}
+
+/// The renderer of a [`CaptionButton`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`CaptionButton`] in your user interface.
+///
+/// [`CaptionButton`]: struct.CaptionButton.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The default size of a [`CaptionButton`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    const DEFAULT_SIZE: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`CaptionButton`].
+    ///
+    /// [`CaptionButton`]: struct.CaptionButton.html
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        kind: Kind,
+        is_disabled: bool,
+        is_pressed: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<CaptionButton<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        caption_button: CaptionButton<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(caption_button)
+    }
+}
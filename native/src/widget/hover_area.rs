@@ -6,8 +6,8 @@
 //! [`State`]: struct.State.html
 use crate::{
     input::{mouse, ButtonState},
-    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, MouseCursor,
+    Point, Rectangle, Widget,
 };
 use std::hash::Hash;
 
@@ -37,6 +37,10 @@ pub struct HoverArea<'a, Message, Renderer: self::Renderer> {
     min_width: u32,
     min_height: u32,
     padding: u16,
+    mouse_cursor: Option<MouseCursor>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_move: Option<Box<dyn Fn(Point) -> Message>>,
     style: Renderer::Style,
 }
 
@@ -62,6 +66,10 @@ where
             min_width: 0,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            mouse_cursor: None,
+            on_enter: None,
+            on_exit: None,
+            on_move: None,
             style: Renderer::Style::default(),
         }
     }
@@ -115,6 +123,52 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the cursor enters the
+    /// bounds of the [`HoverArea`].
+    ///
+    /// This preserves the existing behavior of [`on_hover`]: both are
+    /// produced on the same `false` to `true` transition of the hovered
+    /// state.
+    ///
+    /// [`on_hover`]: #method.on_hover
+    /// [`HoverArea`]: struct.HoverArea.html
+    pub fn on_enter(mut self, msg: Message) -> Self {
+        self.on_enter = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor leaves the
+    /// bounds of the [`HoverArea`].
+    ///
+    /// [`HoverArea`]: struct.HoverArea.html
+    pub fn on_exit(mut self, msg: Message) -> Self {
+        self.on_exit = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced, with the cursor position
+    /// relative to the [`HoverArea`], every time the cursor moves while
+    /// inside its bounds.
+    ///
+    /// [`HoverArea`]: struct.HoverArea.html
+    pub fn on_move<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_move = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the [`MouseCursor`] to show while the [`HoverArea`] is
+    /// hovered.
+    ///
+    /// [`MouseCursor`]: ../../enum.MouseCursor.html
+    /// [`HoverArea`]: struct.HoverArea.html
+    pub fn mouse_cursor(mut self, mouse_cursor: MouseCursor) -> Self {
+        self.mouse_cursor = Some(mouse_cursor);
+        self
+    }
+
     /// Sets the style of the [`HoverArea`].
     ///
     /// [`HoverArea`]: struct.HoverArea.html
@@ -179,7 +233,7 @@ where
 
     fn on_event(
         &mut self,
-        _event: Event,
+        event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
@@ -187,13 +241,33 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
         let bounds = layout.bounds();
-        let old_value = self.state.is_hovered;
-        let new_value = bounds.contains(cursor_position);
-        self.state.is_hovered = new_value;
-        if new_value && new_value != old_value {
+        let was_hovered = self.state.is_hovered;
+        let is_hovered = bounds.contains(cursor_position);
+        self.state.is_hovered = is_hovered;
+
+        if is_hovered && !was_hovered {
             if let Some(on_hover) = self.on_hover.clone() {
                 messages.push(on_hover);
             }
+
+            if let Some(on_enter) = self.on_enter.clone() {
+                messages.push(on_enter);
+            }
+        } else if !is_hovered && was_hovered {
+            if let Some(on_exit) = self.on_exit.clone() {
+                messages.push(on_exit);
+            }
+        }
+
+        if is_hovered {
+            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
+                if let Some(on_move) = &self.on_move {
+                    messages.push(on_move(Point::new(
+                        x - bounds.x,
+                        y - bounds.y,
+                    )));
+                }
+            }
         }
     }
 
@@ -210,6 +284,11 @@ where
             cursor_position,
             self.on_hover.is_none(),
             self.state.is_hovered,
+            if self.state.is_hovered {
+                self.mouse_cursor
+            } else {
+                None
+            },
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -240,6 +319,10 @@ pub trait Renderer: crate::Renderer + Sized {
 
     /// Draws a [`HoverArea`].
     ///
+    /// The renderer should aggregate `mouse_cursor`, when `Some`, into its
+    /// `Output` alongside the drawn primitives so it wins over any
+    /// `MouseCursor` reported by widgets underneath.
+    ///
     /// [`HoverArea`]: struct.HoverArea.html
     fn draw<Message>(
         &mut self,
@@ -248,6 +331,7 @@ pub trait Renderer: crate::Renderer + Sized {
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        mouse_cursor: Option<MouseCursor>,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -0,0 +1,8 @@
+//! Display information and interactive controls in your application.
+pub mod caption_button;
+pub mod hover_area;
+pub mod tooltip;
+
+pub use caption_button::CaptionButton;
+pub use hover_area::HoverArea;
+pub use tooltip::Tooltip;
@@ -0,0 +1,309 @@
+//! Display a tooltip near an element after hovering it for a while.
+//!
+//! A [`Tooltip`] wraps some content and shows a second, floating element
+//! next to it once the cursor has been hovering the content for longer
+//! than its [`hover_delay`].
+//!
+//! [`Tooltip`]: struct.Tooltip.html
+//! [`hover_delay`]: struct.Tooltip.html#method.hover_delay
+use crate::{
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
+};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// An element that floats a `tooltip` next to its `content` while hovered.
+///
+/// ```
+/// # use iced_native::{tooltip, Text};
+/// #
+/// # type Tooltip<'a, Message> =
+/// #     iced_native::Tooltip<'a, Message, iced_native::renderer::Null>;
+/// #
+/// let mut state = tooltip::State::new();
+///
+/// Tooltip::new(&mut state, Text::new("Hover me!"), Text::new("I'm a tooltip!"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
+    position: Position,
+    hover_delay: Duration,
+    gap: u16,
+    viewport: Option<Rectangle>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tooltip`] with some local [`State`], the given
+    /// content, and the element that will be displayed as the tooltip.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    pub fn new<C, T>(state: &'a mut State, content: C, tooltip: T) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Tooltip {
+            state,
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position: Position::FollowCursor,
+            hover_delay: Duration::from_millis(500),
+            gap: 0,
+            viewport: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Position`] of the [`Tooltip`].
+    ///
+    /// [`Position`]: enum.Position.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets how long the cursor must hover the content before the
+    /// [`Tooltip`] is shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn hover_delay(mut self, hover_delay: Duration) -> Self {
+        self.hover_delay = hover_delay;
+        self
+    }
+
+    /// Sets the gap, in pixels, between the content and the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the viewport [`Rectangle`] the [`Tooltip`] must be clamped
+    /// into, in the same coordinate space as `layout`.
+    ///
+    /// If it is never set, the [`Tooltip`] is drawn without clamping,
+    /// which can let it spill outside of the window.
+    ///
+    /// [`Rectangle`]: ../struct.Rectangle.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn viewport(mut self, viewport: Rectangle) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the `Instant` at which the [`Tooltip`] should be redrawn
+    /// in order to appear, if the cursor is currently hovering the
+    /// content and the [`Tooltip`] is not visible yet.
+    ///
+    /// A windowing runtime can poll this after every redraw and use it
+    /// to schedule the next one (e.g. `ControlFlow::WaitUntil`), so the
+    /// [`Tooltip`] still appears even if the cursor stops moving once
+    /// it is inside the content's bounds.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn wake_at(&self) -> Option<Instant> {
+        if self.state.is_hovered && !self.state.is_visible(self.hover_delay) {
+            self.state.hover_started_at.map(|started_at| started_at + self.hover_delay)
+        } else {
+            None
+        }
+    }
+}
+
+/// The position of a [`Tooltip`] relative to its content.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The tooltip follows the cursor.
+    FollowCursor,
+    /// The tooltip is displayed above the content.
+    Top,
+    /// The tooltip is displayed below the content.
+    Bottom,
+    /// The tooltip is displayed to the left of the content.
+    Left,
+    /// The tooltip is displayed to the right of the content.
+    Right,
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_hovered: bool,
+    hover_started_at: Option<Instant>,
+    cursor_position: Point,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns `true` once the cursor has been hovering the content for
+    /// longer than `hover_delay`.
+    fn is_visible(&self, hover_delay: Duration) -> bool {
+        match self.hover_started_at {
+            Some(started_at) => {
+                self.is_hovered && started_at.elapsed() >= hover_delay
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // The tooltip floats on top of the layout and must not influence
+        // the size the `Tooltip` reports to its parent.
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let was_hovered = self.state.is_hovered;
+        let is_hovered = bounds.contains(cursor_position);
+
+        self.state.is_hovered = is_hovered;
+        self.state.cursor_position = cursor_position;
+
+        if is_hovered && !was_hovered {
+            self.state.hover_started_at = Some(Instant::now());
+        } else if !is_hovered {
+            self.state.hover_started_at = None;
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        if self.state.is_visible(self.hover_delay) {
+            renderer.draw(
+                defaults,
+                bounds,
+                self.state.cursor_position,
+                self.position,
+                self.gap,
+                self.viewport,
+                &self.style,
+                &self.content,
+                &self.tooltip,
+                layout,
+            )
+        } else {
+            self.content.draw(renderer, defaults, layout, cursor_position)
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [renderer] will need to implement this trait before being able to
+/// use a [`Tooltip`] in your user interface. The renderer is responsible
+/// for clamping the tooltip so that it stays inside the viewport
+/// [`Rectangle`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [renderer]: ../../renderer/index.html
+/// [`Rectangle`]: ../../struct.Rectangle.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Tooltip`] on top of its `content`.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        position: Position,
+        gap: u16,
+        viewport: Option<Rectangle>,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        tooltip: &Element<'_, Message, Self>,
+        layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
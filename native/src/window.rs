@@ -0,0 +1,25 @@
+//! Interact with your application's window.
+mod action;
+
+pub use action::Action;
+
+/// The mode of a window-based application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The application appears in its own window.
+    Windowed,
+
+    /// The application appears maximized, filling the work area of the
+    /// screen without covering the taskbar.
+    Maximized,
+
+    /// The application takes full, unconditional control of the screen,
+    /// obscuring the taskbar and any other OS elements.
+    Fullscreen,
+}
+
+impl Default for Mode {
+    fn default() -> Mode {
+        Mode::Windowed
+    }
+}
@@ -0,0 +1,27 @@
+use crate::window::Mode;
+
+/// An operation to be performed on some window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Changes the [`Mode`] of the window.
+    ///
+    /// [`Mode`]: ../enum.Mode.html
+    ChangeMode(Mode),
+
+    /// Starts an interactive move of the window, driven by the OS drag
+    /// gesture already in progress.
+    ///
+    /// This is meant to be produced in response to a press on a custom
+    /// title bar, so the operating system takes over the window move the
+    /// same way it would for a system-drawn title bar.
+    Drag,
+
+    /// Minimizes the window.
+    Minimize,
+
+    /// Toggles the window between its maximized and restored state.
+    Maximize,
+
+    /// Closes the window.
+    Close,
+}
@@ -26,9 +26,25 @@ impl From<Settings> for iced_winit::Settings {
                 position: settings.window.position,
                 size: settings.window.size,
                 resizable: settings.window.resizable,
-                decorations: settings.window.decorations,
+                decorations: settings.window.decorations.into(),
+                mode: settings.window.mode,
+                transparent: settings.window.transparent,
                 platform_specific: Default::default(),
             },
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<window::Decorations> for iced_winit::settings::Decorations {
+    fn from(decorations: window::Decorations) -> iced_winit::settings::Decorations {
+        match decorations {
+            window::Decorations::System => {
+                iced_winit::settings::Decorations::System
+            }
+            window::Decorations::Custom { title_bar_height } => {
+                iced_winit::settings::Decorations::Custom { title_bar_height }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Configure your application windows.
+pub use iced_native::window::Mode;
+
+/// The settings of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The initial position of the window.
+    pub position: Option<(u32, u32)>,
+
+    /// The initial size of the window.
+    pub size: (u32, u32),
+
+    /// Whether the window should be resizable or not.
+    pub resizable: bool,
+
+    /// The [`Decorations`] of the window.
+    ///
+    /// [`Decorations`]: enum.Decorations.html
+    pub decorations: Decorations,
+
+    /// The initial [`Mode`] of the window.
+    ///
+    /// Not yet wired up to any windowing backend: no shipped
+    /// `iced_winit` window construction calls `set_maximized` or
+    /// `set_fullscreen`, and nothing executes a
+    /// [`window::Action::ChangeMode`] produced by [`Command::set_mode`]
+    /// at runtime, so this only affects what the value reads back as.
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`window::Action::ChangeMode`]: ../../iced_native/window/enum.Action.html#variant.ChangeMode
+    /// [`Command::set_mode`]: ../../iced_native/command/struct.Command.html#method.set_mode
+    pub mode: Mode,
+
+    /// Whether the window should have a transparent background.
+    ///
+    /// This is not wired up to any renderer backend yet: no shipped
+    /// `iced_wgpu` compositor configures an alpha-capable surface format
+    /// or clears to a premultiplied transparent color, so setting this to
+    /// `true` currently has no visible effect.
+    pub transparent: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            position: None,
+            size: (1024, 768),
+            resizable: true,
+            decorations: Decorations::System,
+            mode: Mode::Windowed,
+            transparent: false,
+        }
+    }
+}
+
+/// The title bar decorations of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decorations {
+    /// The window uses the operating system's native title bar and
+    /// border.
+    System,
+
+    /// The application draws its own title bar.
+    ///
+    /// The application is responsible for painting the title bar itself
+    /// inside the reserved `title_bar_height`, and for turning presses on
+    /// its own caption buttons into the window actions it wants (e.g. via
+    /// [`CaptionButton`] and [`Command`]).
+    ///
+    /// [`CaptionButton`]: ../../iced_native/widget/caption_button/struct.CaptionButton.html
+    /// [`Command`]: ../../iced_native/command/struct.Command.html
+    Custom {
+        /// The height, in pixels, reserved for the custom title bar.
+        title_bar_height: u16,
+    },
+}
+
+impl Default for Decorations {
+    fn default() -> Decorations {
+        Decorations::System
+    }
+}
@@ -0,0 +1,50 @@
+//! Change the appearance of a caption button.
+use iced_core::{Background, Color};
+
+/// The appearance of a caption button.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The icon color of the caption button.
+    pub icon_color: Color,
+    /// The background of the caption button.
+    pub background: Option<Background>,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            icon_color: Color::BLACK,
+            background: None,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a caption button.
+pub trait StyleSheet {
+    /// Produces the style of an active caption button.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered caption button.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// Produces the style of a pressed caption button.
+    fn pressed(&self) -> Style {
+        self.hovered()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
@@ -3,6 +3,7 @@
 //! It contains a set of styles and stylesheets for most of the built-in
 //! widgets.
 pub mod button;
+pub mod caption_button;
 pub mod checkbox;
 pub mod container;
 pub mod hover_area;
@@ -11,3 +12,4 @@ pub mod radio;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod tooltip;
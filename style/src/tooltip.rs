@@ -0,0 +1,49 @@
+//! Change the appearance of a tooltip.
+use iced_core::{Background, Color};
+
+/// The appearance of a tooltip.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The text color of the tooltip.
+    pub text_color: Color,
+    /// The background of the tooltip.
+    pub background: Background,
+    /// The border radius of the tooltip.
+    pub border_radius: f32,
+    /// The border width of the tooltip.
+    pub border_width: f32,
+    /// The border color of the tooltip.
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::WHITE,
+            background: Background::Color([0.15, 0.15, 0.15].into()),
+            border_radius: 3.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tooltip.
+pub trait StyleSheet {
+    /// Produces the style of a tooltip.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
@@ -0,0 +1,118 @@
+//! Let your users minimize, maximize, and close the window from a
+//! custom title bar.
+//!
+//! A [`CaptionButton`] has some local [`State`].
+//!
+//! [`CaptionButton`]: type.CaptionButton.html
+//! [`State`]: struct.State.html
+use crate::{Primitive, Renderer};
+use iced_core::{Background, Color};
+use iced_native::{Point, Rectangle};
+
+pub use iced_native::caption_button::{Kind, State};
+pub use iced_native::MouseCursor;
+pub use iced_style::caption_button::{Style, StyleSheet};
+
+/// A widget that produces a message when clicked, mimicking a native
+/// title bar caption button.
+///
+/// This is an alias of an `iced_native` caption_button with an
+/// `iced_wgpu::Renderer`.
+pub type CaptionButton<'a, Message> =
+    iced_native::CaptionButton<'a, Message, Renderer>;
+
+impl iced_native::caption_button::Renderer for Renderer {
+    const DEFAULT_SIZE: u16 = 46;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        kind: Kind,
+        is_disabled: bool,
+        is_pressed: bool,
+        style: &Self::Style,
+    ) -> Self::Output {
+        let is_hovered = !is_disabled && bounds.contains(cursor_position);
+
+        let style = if is_disabled {
+            style.active()
+        } else if is_pressed {
+            style.pressed()
+        } else if is_hovered {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        let stroke = 1.0;
+        let icon_size = (bounds.width.min(bounds.height) / 3.0).round();
+        let icon_bounds = Rectangle {
+            x: bounds.x + (bounds.width - icon_size) / 2.0,
+            y: bounds.y + (bounds.height - icon_size) / 2.0,
+            width: icon_size,
+            height: icon_size,
+        };
+
+        match kind {
+            Kind::Minimize => {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: icon_bounds.y + icon_bounds.height - stroke,
+                        height: stroke,
+                        ..icon_bounds
+                    },
+                    background: Background::Color(style.icon_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+            Kind::Maximize => {
+                primitives.push(Primitive::Quad {
+                    bounds: icon_bounds,
+                    background: Background::Color(Color::TRANSPARENT),
+                    border_radius: 0.0,
+                    border_width: stroke,
+                    border_color: style.icon_color,
+                });
+            }
+            Kind::Close => {
+                // A diagonal cross isn't expressible with an
+                // axis-aligned `Primitive::Quad`, so the close icon is
+                // approximated as a filled square instead of an "X".
+                primitives.push(Primitive::Quad {
+                    bounds: icon_bounds,
+                    background: Background::Color(style.icon_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        (
+            Primitive::Group(primitives),
+            if is_hovered {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::Default
+            },
+        )
+    }
+}
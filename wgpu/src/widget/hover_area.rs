@@ -4,9 +4,11 @@
 //!
 //! [`Button`]: type.Button.html
 //! [`State`]: struct.State.html
-use crate::Renderer;
+use crate::{Primitive, Renderer};
+use iced_native::{Element, Layout, Point, Rectangle};
 
 pub use iced_native::hover_area::State;
+pub use iced_native::MouseCursor;
 pub use iced_style::hover_area::{Style, StyleSheet};
 
 /// A widget that produces a message when clicked.
@@ -14,3 +16,44 @@ pub use iced_style::hover_area::{Style, StyleSheet};
 /// This is an alias of an `iced_native` hover_area with an
 /// `iced_wgpu::Renderer`.
 pub type HoverArea<'a, Message> = iced_native::HoverArea<'a, Message, Renderer>;
+
+impl iced_native::hover_area::Renderer for Renderer {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        _is_disabled: bool,
+        is_hovered: bool,
+        mouse_cursor: Option<MouseCursor>,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content_primitive, content_cursor) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        let style = if is_hovered {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        (
+            Primitive::Group(vec![background, content_primitive]),
+            mouse_cursor.unwrap_or(content_cursor),
+        )
+    }
+}
@@ -0,0 +1,107 @@
+//! Display a tooltip near an element after hovering it for a while.
+//!
+//! A [`Tooltip`] wraps some content and a tooltip element.
+//!
+//! [`Tooltip`]: type.Tooltip.html
+use crate::{Primitive, Renderer};
+use iced_native::tooltip::Position;
+use iced_native::{layout, Element, Layout, Point, Rectangle, Size};
+
+pub use iced_native::tooltip::State;
+pub use iced_style::tooltip::{Style, StyleSheet};
+
+/// A widget that floats a tooltip near some content.
+///
+/// This is an alias of an `iced_native` tooltip with an
+/// `iced_wgpu::Renderer`.
+pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Renderer>;
+
+impl iced_native::tooltip::Renderer for Renderer {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        position: Position,
+        gap: u16,
+        viewport: Option<Rectangle>,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        tooltip: &Element<'_, Message, Self>,
+        layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content_primitive, mouse_cursor) =
+            content.draw(self, defaults, layout, cursor_position);
+
+        let limits = layout::Limits::new(
+            Size::new(0.0, 0.0),
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+        let mut tooltip_node = tooltip.layout(self, &limits);
+        let tooltip_size = tooltip_node.size();
+        let gap = f32::from(gap);
+
+        let (mut x, mut y) = match position {
+            Position::FollowCursor => {
+                (cursor_position.x, cursor_position.y + gap)
+            }
+            Position::Top => (
+                bounds.x + bounds.width / 2.0 - tooltip_size.width / 2.0,
+                bounds.y - tooltip_size.height - gap,
+            ),
+            Position::Bottom => (
+                bounds.x + bounds.width / 2.0 - tooltip_size.width / 2.0,
+                bounds.y + bounds.height + gap,
+            ),
+            Position::Left => (
+                bounds.x - tooltip_size.width - gap,
+                bounds.y + bounds.height / 2.0 - tooltip_size.height / 2.0,
+            ),
+            Position::Right => (
+                bounds.x + bounds.width + gap,
+                bounds.y + bounds.height / 2.0 - tooltip_size.height / 2.0,
+            ),
+        };
+
+        if let Some(viewport) = viewport {
+            let max_x = (viewport.x + viewport.width - tooltip_size.width)
+                .max(viewport.x);
+            let max_y = (viewport.y + viewport.height - tooltip_size.height)
+                .max(viewport.y);
+
+            x = x.max(viewport.x).min(max_x);
+            y = y.max(viewport.y).min(max_y);
+        }
+
+        tooltip_node.bounds.x = x;
+        tooltip_node.bounds.y = y;
+
+        let style = style.style();
+
+        let background = Primitive::Quad {
+            bounds: tooltip_node.bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let (tooltip_primitive, _) = tooltip.draw(
+            self,
+            defaults,
+            Layout::new(&tooltip_node),
+            cursor_position,
+        );
+
+        (
+            Primitive::Group(vec![
+                content_primitive,
+                background,
+                tooltip_primitive,
+            ]),
+            mouse_cursor,
+        )
+    }
+}
@@ -37,8 +37,23 @@ pub struct Window {
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
-    /// Whether the window should have a border, a title bar, etc.
-    pub decorations: bool,
+    /// The [`Decorations`] of the window.
+    ///
+    /// [`Decorations`]: enum.Decorations.html
+    pub decorations: Decorations,
+
+    /// The initial [`Mode`] of the window.
+    ///
+    /// [`Mode`]: ../../../iced_native/window/enum.Mode.html
+    pub mode: iced_native::window::Mode,
+
+    /// Whether the window should have a transparent background.
+    ///
+    /// Not yet wired up to any renderer backend: see
+    /// [`iced::window::Settings::transparent`] for details.
+    ///
+    /// [`iced::window::Settings::transparent`]: ../../../iced/window/struct.Settings.html#structfield.transparent
+    pub transparent: bool,
 
     /// Platform specific settings.
     pub platform_specific: platform::PlatformSpecific,
@@ -50,8 +65,40 @@ impl Default for Window {
             position: None,
             size: (1024, 768),
             resizable: true,
-            decorations: true,
+            decorations: Decorations::System,
+            mode: iced_native::window::Mode::Windowed,
+            transparent: false,
             platform_specific: Default::default(),
         }
     }
 }
+
+/// The title bar decorations of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decorations {
+    /// The window uses the operating system's native title bar and
+    /// border.
+    System,
+
+    /// The application draws its own title bar.
+    ///
+    /// The application is responsible for painting the title bar inside
+    /// the reserved `title_bar_height` and for returning the
+    /// [`Command`] produced by a [`CaptionButton`]'s
+    /// [`Kind::to_command`] from `update` so the window actually
+    /// minimizes, maximizes, or closes.
+    ///
+    /// [`Command`]: ../../../iced_native/command/struct.Command.html
+    /// [`CaptionButton`]: ../../../iced_native/widget/caption_button/struct.CaptionButton.html
+    /// [`Kind::to_command`]: ../../../iced_native/widget/caption_button/enum.Kind.html#method.to_command
+    Custom {
+        /// The height, in pixels, reserved for the custom title bar.
+        title_bar_height: u16,
+    },
+}
+
+impl Default for Decorations {
+    fn default() -> Decorations {
+        Decorations::System
+    }
+}
@@ -0,0 +1,5 @@
+//! Platform specific settings that are not available on Windows.
+
+/// The platform specific window settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlatformSpecific {}
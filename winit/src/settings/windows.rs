@@ -0,0 +1,22 @@
+//! Windows platform specific settings.
+
+/// The platform specific window settings of Windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformSpecific {
+    /// Whether `WM_NCHITTEST` is to be answered from the drag and caption
+    /// button regions of a custom title bar.
+    ///
+    /// No `WM_NCHITTEST` handling and no title bar region registry exist,
+    /// so setting this to `true` has no effect: a window using
+    /// [`Decorations::Custom`] gets no OS-driven drag or snap-layout
+    /// preview either way.
+    ///
+    /// [`Decorations::Custom`]: ../enum.Decorations.html#variant.Custom
+    pub hit_test: bool,
+}
+
+impl Default for PlatformSpecific {
+    fn default() -> Self {
+        Self { hit_test: false }
+    }
+}